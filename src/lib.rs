@@ -1,15 +1,35 @@
 use std::error::Error;
-use std::{env, fs};
+use std::io::Read;
+use std::{env, fs, io};
 
 /// the configuration struct
 /// for the search process
 pub struct Config {
     /// the query to search for
     pub query: String,
-    /// the file path to search its content
-    pub file_path: String,
+    /// the file paths to search their content;
+    /// an empty list means reading from standard input
+    pub file_paths: Vec<String>,
     /// determines  the search process case sensitivity
     pub ignore_case: bool,
+    /// prefixes each printed line with its 1-based line number
+    pub line_number: bool,
+    /// prints only the count of matching lines instead of the lines
+    pub count: bool,
+    /// keeps the lines that do NOT match the query
+    pub invert: bool,
+    /// selects how the query is matched against each line
+    pub match_mode: MatchMode,
+}
+
+/// how the query is compared against a line
+pub enum MatchMode {
+    /// the line contains the query anywhere (the default)
+    Contains,
+    /// the whole trimmed line equals the query
+    WholeLine,
+    /// the query appears surrounded by non-alphanumeric boundaries
+    Word,
 }
 
 impl Config {
@@ -29,17 +49,40 @@ impl Config {
             None => return Err("Didn't get a query string"),
         };
 
-        let file_path = match args.next() {
-            Some(arg) => arg,
-            None => return Err("Didn't get a file path"),
-        };
+        // the env var is only a fallback; an explicit flag wins over it.
+        let mut ignore_case = env::var("IGNORE_CASE").is_ok();
+        let mut line_number = false;
+        let mut count = false;
+        let mut invert = false;
+        let mut match_mode = MatchMode::Contains;
 
-        let ignore_case = env::var("IGNORE_CASE").is_ok();
+        // scan the remaining items for known flags rather than assuming
+        // fixed positions, since `args` is a generic iterator. Unknown
+        // tokens starting with `-` are rejected; anything else is treated
+        // as a file path; an empty list means we'll read from standard input.
+        let mut file_paths: Vec<String> = Vec::new();
+        for arg in args {
+            match arg.as_str() {
+                "-i" => ignore_case = true,
+                "-s" => ignore_case = false,
+                "-n" => line_number = true,
+                "-c" => count = true,
+                "-v" => invert = true,
+                "-x" => match_mode = MatchMode::WholeLine,
+                "-w" => match_mode = MatchMode::Word,
+                _ if arg.starts_with('-') => return Err("Got an unknown argument"),
+                _ => file_paths.push(arg),
+            }
+        }
 
         Ok(Config {
             query,
-            file_path,
+            file_paths,
             ignore_case,
+            line_number,
+            count,
+            invert,
+            match_mode,
         })
     }
 }
@@ -54,40 +97,147 @@ impl Config {
 /// ```
 ///
 pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
-    let content = fs::read_to_string(config.file_path)?;
+    // grep prefixes matches with the file name only when more than one
+    // source is searched, so it can disambiguate them.
+    let show_names = config.file_paths.len() > 1;
 
-    let result = if config.ignore_case {
-        search_case_insensitive(&config.query, &content)
+    if config.file_paths.is_empty() {
+        let mut content = String::new();
+        io::stdin().read_to_string(&mut content)?;
+        print_matches(&config, None, &content);
     } else {
-        search(&config.query, &content)
-    };
+        for file_path in &config.file_paths {
+            let content = fs::read_to_string(file_path)?;
+            let name = if show_names { Some(file_path.as_str()) } else { None };
+            print_matches(&config, name, &content);
+        }
+    }
+
+    Ok(())
+}
 
-    for line in result {
+/// prints the matches found in `content` honoring the output modes in
+/// `config`, optionally prefixing each line with `name`.
+fn print_matches(config: &Config, name: Option<&str>, content: &str) {
+    for line in render_matches(config, name, content) {
         println!("{line}");
     }
+}
 
-    Ok(())
+/// renders the output lines for `content` honoring the output modes in
+/// `config`, optionally prefixing each line with `name`. Kept pure (no
+/// I/O) so the prefixing and numbering behavior is testable.
+fn render_matches(config: &Config, name: Option<&str>, content: &str) -> Vec<String> {
+    let result = search(&config.query, content, config);
+
+    if config.count {
+        return match name {
+            Some(name) => vec![format!("{name}:{}", result.len())],
+            None => vec![result.len().to_string()],
+        };
+    }
+
+    result
+        .into_iter()
+        .map(|(index, line)| {
+            let prefix = match name {
+                Some(name) => format!("{name}:"),
+                None => String::new(),
+            };
+            if config.line_number {
+                format!("{prefix}{}:{line}", index + 1)
+            } else {
+                format!("{prefix}{line}")
+            }
+        })
+        .collect()
 }
 
-fn search<'a, 'b>(query: &str, content: &'a str) -> Vec<&'a str> {
-    return content
+/// searches `content` for `query` honoring the case, inversion and match
+/// mode options carried by `config`, returning the 0-based line index
+/// paired with the original slice of each kept line.
+fn search<'a>(query: &str, content: &'a str, config: &Config) -> Vec<(usize, &'a str)> {
+    // fold the case up front when requested so each line is only
+    // lowercased once during the scan.
+    let query = if config.ignore_case {
+        query.to_lowercase()
+    } else {
+        query.to_string()
+    };
+
+    content
         .lines()
-        .filter(|line| line.contains(query))
-        .collect();
+        .enumerate()
+        .filter(|(_, line)| {
+            // only allocate when case folding; the hot case-sensitive
+            // path borrows the line directly.
+            let matched = if config.ignore_case {
+                line_matches(&line.to_lowercase(), &query, &config.match_mode)
+            } else {
+                line_matches(line, &query, &config.match_mode)
+            };
+            matched != config.invert
+        })
+        .collect()
 }
 
-fn search_case_insensitive<'a>(query: &str, content: &'a str) -> Vec<&'a str> {
-    let query = query.to_lowercase();
-    return content
-        .lines()
-        .filter(|line| line.contains(&query))
-        .collect();
+/// applies `mode` to test whether `line` matches `query`.
+fn line_matches(line: &str, query: &str, mode: &MatchMode) -> bool {
+    match mode {
+        MatchMode::Contains => line.contains(query),
+        MatchMode::WholeLine => line.trim() == query,
+        MatchMode::Word => word_match(line, query),
+    }
+}
+
+/// returns true when `query` appears in `line` bounded by non-alphanumeric
+/// characters or the ends of the string.
+fn word_match(line: &str, query: &str) -> bool {
+    if query.is_empty() {
+        return false;
+    }
+
+    let mut start = 0;
+    while let Some(pos) = line[start..].find(query) {
+        let begin = start + pos;
+        let end = begin + query.len();
+
+        // inspect the adjacent chars (not bytes) so a multi-byte letter
+        // next to the query still counts as a word character.
+        let left_ok = line[..begin].chars().next_back().map_or(true, |c| !is_word_char(c));
+        let right_ok = line[end..].chars().next().map_or(true, |c| !is_word_char(c));
+        if left_ok && right_ok {
+            return true;
+        }
+
+        // advance by one char (not the whole query) so an overlapping
+        // occurrence starting inside this one is still considered.
+        start = begin + line[begin..].chars().next().map_or(1, |c| c.len_utf8());
+    }
+    false
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    /// builds a minimal config for exercising `search` directly.
+    fn cfg(ignore_case: bool, invert: bool, match_mode: MatchMode) -> Config {
+        Config {
+            query: String::new(),
+            file_paths: Vec::new(),
+            ignore_case,
+            line_number: false,
+            count: false,
+            invert,
+            match_mode,
+        }
+    }
+
     #[test]
     fn case_sensitive() {
         let query = "duct";
@@ -96,7 +246,83 @@ Rust:
 safe, fast, productive.
 Pick three.
 Duct tape.";
-        assert_eq!(vec!["safe, fast, productive."], search(query, contents));
+        assert_eq!(
+            vec![(1, "safe, fast, productive.")],
+            search(query, contents, &cfg(false, false, MatchMode::Contains))
+        );
+    }
+
+    #[test]
+    fn flag_only_sets_ignore_case() {
+        let args = vec![
+            String::from("cwf"),
+            String::from("query"),
+            String::from("file"),
+            String::from("-i"),
+        ];
+        let config = Config::build(args.into_iter()).unwrap();
+        assert!(config.ignore_case);
+    }
+
+    // `IGNORE_CASE` is process-global and cargo runs tests in parallel,
+    // so all env-dependent assertions live in this single test to avoid
+    // one test's `remove_var` racing another's `set_var`.
+    #[test]
+    fn env_var_sets_case_and_flag_overrides() {
+        env::set_var("IGNORE_CASE", "1");
+
+        // env only: used as the fallback.
+        let args = vec![
+            String::from("cwf"),
+            String::from("query"),
+            String::from("file"),
+        ];
+        let config = Config::build(args.into_iter()).unwrap();
+        assert!(config.ignore_case);
+
+        // explicit `-s` wins over the env var.
+        let args = vec![
+            String::from("cwf"),
+            String::from("query"),
+            String::from("file"),
+            String::from("-s"),
+        ];
+        let config = Config::build(args.into_iter()).unwrap();
+        assert!(!config.ignore_case);
+
+        env::remove_var("IGNORE_CASE");
+    }
+
+    #[test]
+    fn collects_multiple_file_paths() {
+        let args = vec![
+            String::from("cwf"),
+            String::from("query"),
+            String::from("a.txt"),
+            String::from("b.txt"),
+            String::from("-i"),
+        ];
+        let config = Config::build(args.into_iter()).unwrap();
+        assert_eq!(vec!["a.txt", "b.txt"], config.file_paths);
+        assert!(config.ignore_case);
+    }
+
+    #[test]
+    fn single_file_path() {
+        let args = vec![
+            String::from("cwf"),
+            String::from("query"),
+            String::from("a.txt"),
+        ];
+        let config = Config::build(args.into_iter()).unwrap();
+        assert_eq!(vec!["a.txt"], config.file_paths);
+    }
+
+    #[test]
+    fn no_file_path_means_stdin() {
+        let args = vec![String::from("cwf"), String::from("query")];
+        let config = Config::build(args.into_iter()).unwrap();
+        assert!(config.file_paths.is_empty());
     }
 
     #[test]
@@ -109,8 +335,163 @@ Pick three.
 Trust me.";
 
         assert_eq!(
-            vec!["Rust:", "Trust me."],
-            search_case_insensitive(query, contents)
+            vec![(0, "Rust:"), (3, "Trust me.")],
+            search(query, contents, &cfg(true, false, MatchMode::Contains))
+        );
+    }
+
+    #[test]
+    fn insensitive_matches_mixed_case() {
+        let query = "RuSt";
+        let contents = "\
+safe, fast, productive.
+Trust me.";
+
+        assert_eq!(
+            vec![(1, "Trust me.")],
+            search(query, contents, &cfg(true, false, MatchMode::Contains))
+        );
+    }
+
+    #[test]
+    fn line_numbers_are_one_based() {
+        let query = "me";
+        let contents = "\
+Rust:
+safe, fast, productive.
+Trust me.";
+
+        let result = search(query, contents, &cfg(false, false, MatchMode::Contains));
+        assert_eq!(vec![(2, "Trust me.")], result);
+
+        // `search` yields the 0-based index; the `-n` output shows it 1-based.
+        let mut config = cfg(false, false, MatchMode::Contains);
+        config.query = String::from(query);
+        config.line_number = true;
+        assert_eq!(
+            vec![String::from("3:Trust me.")],
+            render_matches(&config, None, contents)
+        );
+    }
+
+    #[test]
+    fn count_of_matching_lines() {
+        let query = "o";
+        let contents = "\
+one
+two
+three";
+
+        assert_eq!(
+            2,
+            search(query, contents, &cfg(false, false, MatchMode::Contains)).len()
+        );
+    }
+
+    #[test]
+    fn invert_keeps_non_matching_lines() {
+        let query = "duct";
+        let contents = "\
+safe, fast, productive.
+Pick three.";
+
+        assert_eq!(
+            vec![(1, "Pick three.")],
+            search(query, contents, &cfg(false, true, MatchMode::Contains))
+        );
+    }
+
+    #[test]
+    fn whole_line_matches_trimmed_line() {
+        let query = "Pick three.";
+        let contents = "\
+Pick three.
+Pick three. And more.";
+
+        assert_eq!(
+            vec![(0, "Pick three.")],
+            search(query, contents, &cfg(false, false, MatchMode::WholeLine))
+        );
+    }
+
+    #[test]
+    fn word_boundary_matches_standalone_word() {
+        let query = "fast";
+        let contents = "\
+safe, fast, productive.
+steadfastly.";
+
+        assert_eq!(
+            vec![(0, "safe, fast, productive.")],
+            search(query, contents, &cfg(false, false, MatchMode::Word))
+        );
+    }
+
+    #[test]
+    fn word_boundary_handles_non_ascii() {
+        // the `café` line makes the query match but fail the boundary
+        // test, so the scan must advance past the multi-byte char
+        // without slicing inside it.
+        let query = "é";
+        let contents = "\
+café
+é alone";
+
+        assert_eq!(
+            vec![(1, "é alone")],
+            search(query, contents, &cfg(false, false, MatchMode::Word))
+        );
+    }
+
+    #[test]
+    fn word_boundary_rejects_non_ascii_neighbor() {
+        // `cat` is inside `écat`; the preceding `é` is a word char, so
+        // this must not be treated as a standalone word.
+        let query = "cat";
+        let contents = "\
+écat
+the cat sat";
+
+        assert_eq!(
+            vec![(1, "the cat sat")],
+            search(query, contents, &cfg(false, false, MatchMode::Word))
+        );
+    }
+
+    #[test]
+    fn invert_with_ignore_case() {
+        let query = "RUST";
+        let contents = "\
+Rust:
+Pick three.";
+
+        assert_eq!(
+            vec![(1, "Pick three.")],
+            search(query, contents, &cfg(true, true, MatchMode::Contains))
+        );
+    }
+
+    #[test]
+    fn multi_file_output_is_prefixed() {
+        let mut config = cfg(false, false, MatchMode::Contains);
+        config.query = String::from("duct");
+        let contents = "safe, fast, productive.";
+
+        assert_eq!(
+            vec![String::from("a.txt:safe, fast, productive.")],
+            render_matches(&config, Some("a.txt"), contents)
+        );
+    }
+
+    #[test]
+    fn single_file_output_has_no_prefix() {
+        let mut config = cfg(false, false, MatchMode::Contains);
+        config.query = String::from("duct");
+        let contents = "safe, fast, productive.";
+
+        assert_eq!(
+            vec![String::from("safe, fast, productive.")],
+            render_matches(&config, None, contents)
         );
     }
 }